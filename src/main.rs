@@ -1,59 +1,121 @@
 use std::fs::File;
-use std::io::{BufReader, BufRead};
+use std::io::{BufReader, BufRead, Write};
 use clap::Parser;
 mod union_find;
 use union_find::client::BulkConnection;
-use union_find::client::Client;
+use union_find::client::StringClient as Client;
 
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 struct Args {
-    /// Name of the file with node names by index
+    /// Name of the file with node names by index, not needed when --snapshot-in is given
     #[clap(short, long, value_name = "FILE")]
-    nodes: String,
+    nodes: Option<String>,
 
-    /// Name of the file with node connections by index
+    /// Name of the file with node connections by index, not needed when --snapshot-in is given
     #[clap(short, long, value_name = "FILE")]
-    connections: String,
+    connections: Option<String>,
+
+    /// Snapshot file to resume the forest from, skipping the node/connection files
+    #[clap(long, value_name = "FILE")]
+    snapshot_in: Option<String>,
+
+    /// Snapshot file to write the fully-processed forest to, for a later --snapshot-in run
+    #[clap(long, value_name = "FILE")]
+    snapshot_out: Option<String>,
+
+    /// File to write one line per connected component to, each a comma-separated list of node names
+    #[clap(long, value_name = "FILE")]
+    components_out: Option<String>
 }
 
 fn main() {
     let args = Args::parse();
-    let mut client = Client::new();
 
-    println!("\nNode File: {}", args.nodes);
-    let mut nodes: Vec<String> = vec![];
+    let mut client = match &args.snapshot_in {
+        Some(snapshot_in) => load_snapshot(snapshot_in),
+        None => Client::new()
+    };
+
+    if args.snapshot_in.is_none() {
+        let nodes_path = args.nodes.expect("--nodes is required unless --snapshot-in is given");
+        println!("\nNode File: {}", nodes_path);
+        let mut nodes: Vec<String> = vec![];
+
+        let node_file = File::open(nodes_path).unwrap();
+        let node_reader = BufReader::new(node_file);
 
-    let node_file = File::open(args.nodes).unwrap();
-    let node_reader = BufReader::new(node_file);
+        println!("Processing nodes file...");
+        for line in node_reader.lines() {
+            nodes.push(line.unwrap());
+        }
+        println!("Nodes file processed");
 
-    println!("Processing nodes file...");
-    for line in node_reader.lines() {
-        nodes.push(line.unwrap());
+        println!("Bulk adding nodes...");
+        client.add_nodes_bulk(nodes);
+        println!("Nodes bulk added");
+
+        let connections_path = args.connections.expect("--connections is required unless --snapshot-in is given");
+        println!("\nConnections File: {}", connections_path);
+        let mut connections: Vec<BulkConnection> = vec![];
+
+        let connection_file = File::open(connections_path).unwrap();
+        let connection_reader = BufReader::new(connection_file);
+
+        println!("Processing connections file...");
+        for line in connection_reader.lines() {
+            connections.push(convert_connection(line.unwrap()))
+        }
+        println!("Connections file processed");
+
+        println!("Bulk connecting nodes...");
+        client.connect_nodes_bulk(connections);
+        println!("Nodes bulk connected");
     }
-    println!("Nodes file processed");
 
-    println!("Bulk adding nodes...");
-    client.add_nodes_bulk(nodes);
-    println!("Nodes bulk added");
+    println!("\nDisjoint sets found: {}", client.disjoint_set_count());
+
+    if let Some(snapshot_out) = &args.snapshot_out {
+        save_snapshot(&client, snapshot_out);
+    }
 
-    println!("\nConnections File: {}", args.connections);
-    let mut connections: Vec<BulkConnection> = vec![];
+    if let Some(components_out) = &args.components_out {
+        write_components(&mut client, components_out);
+    }
+}
 
-    let connection_file = File::open(args.connections).unwrap();
-    let connection_reader = BufReader::new(connection_file);
+fn write_components(client: &mut Client, path: &str) {
+    println!("\nComponents Out: {}", path);
+    let mut components_file = File::create(path).unwrap();
 
-    println!("Processing connections file...");
-    for line in connection_reader.lines() {
-        connections.push(convert_connection(line.unwrap()))
+    for members in client.components().values() {
+        let line = members.iter().map(|uuid| uuid.as_str()).collect::<Vec<&str>>().join(",");
+        writeln!(components_file, "{}", line).unwrap();
     }
-    println!("Connections file processed");
+}
+
+#[cfg(feature = "serde")]
+fn load_snapshot(path: &str) -> Client {
+    println!("\nSnapshot In: {}", path);
+    let snapshot_file = File::open(path).unwrap();
+    Client::load_from_reader(BufReader::new(snapshot_file)).unwrap()
+}
 
-    println!("Bulk connecting nodes...");
-    client.connect_nodes_bulk(connections);
-    println!("Nodes bulk connected");
+#[cfg(not(feature = "serde"))]
+fn load_snapshot(_path: &str) -> Client {
+    panic!("--snapshot-in requires the crate to be built with the \"serde\" feature");
+}
 
-    println!("\nDisjoint sets found: {}", client.disjoint_set_count())
+#[cfg(feature = "serde")]
+fn save_snapshot(client: &Client, path: &str) {
+    println!("\nSnapshot Out: {}", path);
+    let snapshot_file = File::create(path).unwrap();
+    client.save_to_writer(snapshot_file).unwrap();
+}
+
+#[cfg(not(feature = "serde"))]
+fn save_snapshot(_client: &Client, _path: &str) {
+    panic!("--snapshot-out requires the crate to be built with the \"serde\" feature");
 }
 
 fn convert_connection(line: String) -> BulkConnection {
@@ -63,4 +125,4 @@ fn convert_connection(line: String) -> BulkConnection {
     let b = connection.1.parse::<usize>().unwrap();
 
     BulkConnection::new(a, b)
-}
\ No newline at end of file
+}
@@ -3,21 +3,28 @@
 mod client_tests;
 
 use std::collections::HashMap;
+use std::hash::Hash;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// A node in the graph
 #[derive(Hash, Eq, PartialEq, Debug)]
-struct Node {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Node<T> {
     /// Unique ID of the node
-    pub uuid: String,
+    pub uuid: T,
     /// Index for the node's parent
     pub parent_index: usize,
     /// Index where the node is stored
     pub index: usize,
     /// Number items in chain
-    pub size: usize
+    pub size: usize,
+    /// Upper bound on the height of the tree rooted here, used for union-by-rank
+    pub rank: usize
 }
 
 #[derive(Hash, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// A connection between two node indexes
 pub struct BulkConnection {
     /// Index of first connection
@@ -29,39 +36,44 @@ pub struct BulkConnection {
 #[derive(Debug)]
 /// A client that manages a graph of nodes and their connections
 ///
+/// `Client` is generic over the node identifier type `T`, so nodes can be
+/// keyed by anything hashable and cloneable rather than only UTF-8 strings
+/// (integers, fixed-width hash IDs, tuples, ...). [`StringClient`] is a
+/// convenience alias for the identifier type this crate started with.
+///
 /// # Examples
 ///
 /// Named node interfaces
 ///
 /// ``` rust
 /// extern crate cozad_union_find;
-/// use cozad_union_find::union_find::client as ufclient;
+/// use cozad_union_find::union_find::client::StringClient;
 ///
 /// fn main() {
-///    let mut client = ufclient::Client::new();
+///    let mut client = StringClient::new();
 ///
-///    client.add_node("A");
-///    client.add_node("B");
-///    client.add_node("C");
-///    client.add_node("D");
-///    client.add_node("E");
-///    client.add_node("F");
-///    client.add_node("G");
-///    client.add_node("H");
-///    client.add_node("I");
-///    client.add_node("J");
+///    client.add_node(&String::from("A"));
+///    client.add_node(&String::from("B"));
+///    client.add_node(&String::from("C"));
+///    client.add_node(&String::from("D"));
+///    client.add_node(&String::from("E"));
+///    client.add_node(&String::from("F"));
+///    client.add_node(&String::from("G"));
+///    client.add_node(&String::from("H"));
+///    client.add_node(&String::from("I"));
+///    client.add_node(&String::from("J"));
 ///
-///    client.connect_nodes("E", "D");
-///    client.connect_nodes("D", "I");
-///    client.connect_nodes("G", "F");
-///    client.connect_nodes("J", "E");
-///    client.connect_nodes("C", "B");
-///    client.connect_nodes("I", "J");
-///    client.connect_nodes("F", "A");
-///    client.connect_nodes("H", "B");
-///    client.connect_nodes("G", "B");
-///    client.connect_nodes("B", "A");
-///    client.connect_nodes("G", "H");
+///    client.connect_nodes(&String::from("E"), &String::from("D"));
+///    client.connect_nodes(&String::from("D"), &String::from("I"));
+///    client.connect_nodes(&String::from("G"), &String::from("F"));
+///    client.connect_nodes(&String::from("J"), &String::from("E"));
+///    client.connect_nodes(&String::from("C"), &String::from("B"));
+///    client.connect_nodes(&String::from("I"), &String::from("J"));
+///    client.connect_nodes(&String::from("F"), &String::from("A"));
+///    client.connect_nodes(&String::from("H"), &String::from("B"));
+///    client.connect_nodes(&String::from("G"), &String::from("B"));
+///    client.connect_nodes(&String::from("B"), &String::from("A"));
+///    client.connect_nodes(&String::from("G"), &String::from("H"));
 ///
 ///    println!("\nDisjoint sets found: {}", client.disjoint_set_count());
 /// }
@@ -71,22 +83,22 @@ pub struct BulkConnection {
 ///
 /// ``` rust
 /// extern crate cozad_union_find;
-/// use cozad_union_find::union_find::client as ufclient;
+/// use cozad_union_find::union_find::client::StringClient;
 /// use cozad_union_find::union_find::client::BulkConnection as ufconnection;
 ///
 /// fn main() {
 ///
-///    let mut bulk_client = ufclient::Client::new();
+///    let mut bulk_client = StringClient::new();
 ///    let nodes = vec![
-///        String::from("A"), 
-///        String::from("B"), 
+///        String::from("A"),
+///        String::from("B"),
 ///        String::from("C"),
 ///        String::from("D"),
 ///        String::from("E"),
-///        String::from("F"), 
-///        String::from("G"), 
-///        String::from("H"), 
-///        String::from("I"), 
+///        String::from("F"),
+///        String::from("G"),
+///        String::from("H"),
+///        String::from("I"),
 ///        String::from("J")
 ///    ];
 ///    bulk_client.add_nodes_bulk(nodes);
@@ -109,18 +121,22 @@ pub struct BulkConnection {
 ///    println!("\nDisjoint sets found: {}", bulk_client.disjoint_set_count());
 /// }
 /// ```
-pub struct Client {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Client<T: Hash + Eq + Clone + Default> {
     /// Storage for nodes in the graph
-    nodes: Vec<Node>,
+    nodes: Vec<Node<T>>,
     /// Map of names to index
-    node_map: HashMap<String, usize>,
+    node_map: HashMap<T, usize>,
     /// Number of disjoint sets
     set_count: usize
 }
 
+/// A `Client` keyed by the original, UTF-8 string node identifier
+pub type StringClient = Client<String>;
+
 impl BulkConnection {
     /// Constructs a new `BulkConnection`
-    /// 
+    ///
     /// # Arguments
     ///
     /// * `a` - Index of first connection
@@ -133,17 +149,18 @@ impl BulkConnection {
     }
 }
 
-impl Client {
+impl<T: Hash + Eq + Clone + Default> Client<T> {
     /// Constructs a new `Client`
     pub fn new() -> Self {
         let node_map = HashMap::new();
         let mut nodes = Vec::new();
 
-        let root_node = Node { 
-            uuid: String::from("root"), 
-            parent_index: 0, 
+        let root_node = Node {
+            uuid: T::default(),
+            parent_index: 0,
             index: 0,
-            size: 0
+            size: 0,
+            rank: 0
         };
         nodes.push(root_node);
 
@@ -161,96 +178,155 @@ impl Client {
     /// * `uuid` - Unique ID of node
     ///
     #[allow(dead_code)]
-    pub fn add_node(&mut self, uuid: &str) {
+    pub fn add_node(&mut self, uuid: &T) {
         if !self.node_exists(uuid) {
-            let node = Node { 
-                uuid: String::from(uuid), 
-                parent_index: self.nodes.len(), 
+            let node = Node {
+                uuid: uuid.clone(),
+                parent_index: self.nodes.len(),
                 index: self.nodes.len(),
-                size: 1
+                size: 1,
+                rank: 0
             };
-            self.node_map.insert(String::from(uuid), node.index);
+            self.node_map.insert(uuid.clone(), node.index);
             self.nodes.push(node);
             self.set_count += 1;
         }
     }
 
     /// Adds a multiple nodes with a single call
-    /// 
+    ///
     /// # Arguments
     ///
     /// * `uuid_list` - Collection of unique IDs
-    /// 
-    pub fn add_nodes_bulk(&mut self, uuid_list: Vec<String>) {
+    ///
+    pub fn add_nodes_bulk(&mut self, uuid_list: Vec<T>) {
         for uuid in uuid_list.iter() {
-            let node = Node { 
-                uuid: String::from(uuid), 
-                parent_index: self.nodes.len(), 
+            let node = Node {
+                uuid: uuid.clone(),
+                parent_index: self.nodes.len(),
                 index: self.nodes.len(),
-                size: 1
+                size: 1,
+                rank: 0
             };
-            self.node_map.insert(String::from(uuid), node.index);
+            self.node_map.insert(uuid.clone(), node.index);
             self.nodes.push(node);
             self.set_count += 1;
         }
     }
 
-    /// Connects two nodes using their unique id
-    /// 
+    /// Connects two nodes using their unique id, union-by-rank
+    ///
     /// # Arguments
     ///
     /// * `uuid_a` - Unique id first node
     /// * `uuid_b` - Unique id second node
     ///
     #[allow(dead_code)]
-    pub fn connect_nodes(&mut self, uuid_a: &str, uuid_b: &str) {
+    pub fn connect_nodes(&mut self, uuid_a: &T, uuid_b: &T) {
         let uuid_a_root = self.find_root_index(uuid_a);
         let uuid_b_root = self.find_root_index(uuid_b);
 
-        if uuid_a_root == uuid_b_root {
-            return
-        } else {
-            let node_slice = &mut self.nodes[..];
-
-            if node_slice[uuid_a_root].size < node_slice[uuid_b_root].size {
-                node_slice[uuid_a_root].parent_index = uuid_b_root;
-                node_slice[uuid_b_root].size += node_slice[uuid_a_root].size;
-            } else {
-                node_slice[uuid_b_root].parent_index = uuid_a_root;
-                node_slice[uuid_a_root].size += node_slice[uuid_b_root].size;
-            }
+        self.union_by_rank(uuid_a_root, uuid_b_root);
+    }
 
-            self.set_count -= 1;
+    /// Connects a collection of nodes using node indexes to avoid node lookups by name, union-by-rank
+    ///
+    /// # Arguments
+    ///
+    /// * `connections` - Collection of graph connections
+    ///
+    pub fn connect_nodes_bulk(&mut self, connections: Vec<BulkConnection>) {
+        for connection in connections.iter() {
+            let uuid_a_root = self.find_root_index_bulk(connection.a + 1);
+            let uuid_b_root = self.find_root_index_bulk(connection.b + 1);
+
+            self.union_by_rank(uuid_a_root, uuid_b_root);
         }
     }
 
-    /// Connects a collection of nodes using node indexes to avoid node lookups by name
+    /// Connects two nodes using their unique id, union-by-size
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid_a` - Unique id first node
+    /// * `uuid_b` - Unique id second node
+    ///
+    #[allow(dead_code)]
+    pub fn connect_nodes_by_size(&mut self, uuid_a: &T, uuid_b: &T) {
+        let uuid_a_root = self.find_root_index(uuid_a);
+        let uuid_b_root = self.find_root_index(uuid_b);
+
+        self.union_by_size(uuid_a_root, uuid_b_root);
+    }
+
+    /// Connects a collection of nodes using node indexes to avoid node lookups by name, union-by-size
     ///
     /// # Arguments
     ///
     /// * `connections` - Collection of graph connections
-    /// 
-    pub fn connect_nodes_bulk(&mut self, connections: Vec<BulkConnection>) {
+    ///
+    pub fn connect_nodes_bulk_by_size(&mut self, connections: Vec<BulkConnection>) {
         for connection in connections.iter() {
             let uuid_a_root = self.find_root_index_bulk(connection.a + 1);
             let uuid_b_root = self.find_root_index_bulk(connection.b + 1);
 
-            if uuid_a_root == uuid_b_root {
-                //do nothing
-            } else {
-                let node_slice = &mut self.nodes[..];
-
-                if node_slice[uuid_a_root].size < node_slice[uuid_b_root].size {
-                    node_slice[uuid_a_root].parent_index = uuid_b_root;
-                    node_slice[uuid_b_root].size += node_slice[uuid_a_root].size;
-                } else {
-                    node_slice[uuid_b_root].parent_index = uuid_a_root;
-                    node_slice[uuid_a_root].size += node_slice[uuid_b_root].size;
-                }
-    
-                self.set_count -= 1;
-            }
+            self.union_by_size(uuid_a_root, uuid_b_root);
+        }
+    }
+
+    /// Attaches the lower-rank root under the higher-rank root, breaking ties by
+    /// attaching `b` under `a` and bumping `a`'s rank
+    ///
+    /// # Arguments
+    ///
+    /// * `root_a` - Index of first root
+    /// * `root_b` - Index of second root
+    ///
+    fn union_by_rank(&mut self, root_a: usize, root_b: usize) {
+        if root_a == root_b {
+            return
+        }
+
+        let node_slice = &mut self.nodes[..];
+
+        if node_slice[root_a].rank < node_slice[root_b].rank {
+            node_slice[root_a].parent_index = root_b;
+            node_slice[root_b].size += node_slice[root_a].size;
+        } else if node_slice[root_a].rank > node_slice[root_b].rank {
+            node_slice[root_b].parent_index = root_a;
+            node_slice[root_a].size += node_slice[root_b].size;
+        } else {
+            node_slice[root_b].parent_index = root_a;
+            node_slice[root_a].size += node_slice[root_b].size;
+            node_slice[root_a].rank += 1;
         }
+
+        self.set_count -= 1;
+    }
+
+    /// Attaches the smaller-size root under the larger-size root
+    ///
+    /// # Arguments
+    ///
+    /// * `root_a` - Index of first root
+    /// * `root_b` - Index of second root
+    ///
+    fn union_by_size(&mut self, root_a: usize, root_b: usize) {
+        if root_a == root_b {
+            return
+        }
+
+        let node_slice = &mut self.nodes[..];
+
+        if node_slice[root_a].size < node_slice[root_b].size {
+            node_slice[root_a].parent_index = root_b;
+            node_slice[root_b].size += node_slice[root_a].size;
+        } else {
+            node_slice[root_b].parent_index = root_a;
+            node_slice[root_a].size += node_slice[root_b].size;
+        }
+
+        self.set_count -= 1;
     }
 
     /// The number of sets that share no connection with another set
@@ -258,51 +334,76 @@ impl Client {
         self.set_count
     }
 
-    /// Finds the connected node with no parent
-    /// 
+    /// Finds the connected node with no parent, path-halving as it walks so repeated
+    /// queries against the same tree approach constant time
+    ///
     /// # Arguments
     ///
     /// * `uuid` - Unique ID of node to find root of
-    /// 
-    pub fn find_root_index(&self, uuid: &str) -> usize {
+    ///
+    pub fn find_root_index(&mut self, uuid: &T) -> usize {
         let node_index = self.node_index(uuid);
         if node_index > 0 {
-            let mut node = self.nodes.get(node_index).unwrap();
-            while node.parent_index != node.index {
-                node = self.nodes.get(node.parent_index).unwrap();
-            }
-            node.parent_index
+            self.find_root_index_bulk(node_index)
         } else {
             0
         }
     }
 
-    /// Finds the connected node with no parent, optimized to reduce lookups
-    /// 
+    /// Finds the connected node with no parent, optimized to reduce lookups, path-halving
+    /// as it walks
+    ///
     /// # Arguments
     ///
     /// * `node_index` - Index of node to find the root of
-    /// 
-    pub fn find_root_index_bulk(&self, node_index: usize) -> usize {
-        let mut node = self.nodes.get(node_index).unwrap();
-        while node.parent_index != node.index {
-            node = self.nodes.get(node.parent_index).unwrap();
+    ///
+    pub fn find_root_index_bulk(&mut self, node_index: usize) -> usize {
+        let mut current = node_index;
+
+        loop {
+            let parent_index = self.nodes[current].parent_index;
+            if parent_index == current {
+                return current;
+            }
+
+            let grandparent_index = self.nodes[parent_index].parent_index;
+            self.nodes[current].parent_index = grandparent_index;
+            current = grandparent_index;
+        }
+    }
+
+    /// Finds the connected node with no parent without compressing the path, for
+    /// callers that only hold an immutable reference
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - Unique ID of node to find root of
+    ///
+    pub fn find_root_index_readonly(&self, uuid: &T) -> usize {
+        let node_index = self.node_index(uuid);
+        if node_index > 0 {
+            let mut node = self.nodes.get(node_index).unwrap();
+            while node.parent_index != node.index {
+                node = self.nodes.get(node.parent_index).unwrap();
+            }
+            node.parent_index
+        } else {
+            0
         }
-        node.parent_index
     }
 
     /// Determines if two nodes are connected through any path
-    /// 
+    ///
     /// # Arguments
     ///
     /// * `uuid_a` - Unique ID of first connection
     /// * `uuid_b` - Unique ID of second connection
     ///
-    #[allow(dead_code)] 
-    pub fn nodes_connected(&self, uuid_a: &str, uuid_b: &str) -> bool {
-        let uuid_a_root = self.find_root_index(uuid_a);
-        let uuid_b_root = self.find_root_index(uuid_b);
-        
+    #[allow(dead_code)]
+    pub fn nodes_connected(&self, uuid_a: &T, uuid_b: &T) -> bool {
+        let uuid_a_root = self.find_root_index_readonly(uuid_a);
+        let uuid_b_root = self.find_root_index_readonly(uuid_b);
+
         uuid_a_root > 0 && uuid_a_root == uuid_b_root
     }
 
@@ -313,28 +414,118 @@ impl Client {
     }
 
     /// Determines if a node exists by the given name
-    /// 
+    ///
     /// # Arguments
     ///
     /// * `uuid` - Unique ID of node
-    /// 
-    pub fn node_exists(&self, uuid: &str) -> bool {
-        let node_uuid = String::from(uuid);
-        self.node_map.contains_key(&node_uuid)
+    ///
+    pub fn node_exists(&self, uuid: &T) -> bool {
+        self.node_map.contains_key(uuid)
     }
 
     /// Gets the index for a node with a given unique ID
-    /// 
+    ///
     /// # Arguments
     ///
     /// * `uuid` - Unique ID of node
-    /// 
-    pub fn node_index(&self, uuid: &str) -> usize {
-        let node_uuid = String::from(uuid);
-        if self.node_map.contains_key(&node_uuid) {
-            *self.node_map.get(&node_uuid).unwrap()
+    ///
+    pub fn node_index(&self, uuid: &T) -> usize {
+        if let Some(index) = self.node_map.get(uuid) {
+            *index
         } else {
             0
         }
     }
-}
\ No newline at end of file
+
+    /// Groups every node's identifier under its representative root
+    pub fn components(&mut self) -> HashMap<usize, Vec<&T>> {
+        let mut roots: Vec<usize> = vec![0; self.nodes.len()];
+        for index in 1..self.nodes.len() {
+            roots[index] = self.find_root_index_bulk(index);
+        }
+
+        let mut components: HashMap<usize, Vec<&T>> = HashMap::new();
+        for index in 1..self.nodes.len() {
+            components.entry(roots[index]).or_insert_with(Vec::new).push(&self.nodes[index].uuid);
+        }
+
+        components
+    }
+
+    /// Calls `visitor` once per node with its representative root and identifier,
+    /// without buffering every component into memory at once
+    ///
+    /// # Arguments
+    ///
+    /// * `visitor` - Called with the root index and identifier of each node
+    ///
+    pub fn for_each_component<F: FnMut(usize, &T)>(&mut self, mut visitor: F) {
+        for index in 1..self.nodes.len() {
+            let root = self.find_root_index_bulk(index);
+            visitor(root, &self.nodes[index].uuid);
+        }
+    }
+
+    /// The identifiers of every node in the same component as `uuid`
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - Unique ID of node whose component to return
+    ///
+    pub fn component_of(&mut self, uuid: &T) -> Vec<&T> {
+        let node_index = self.node_index(uuid);
+        if node_index == 0 {
+            return Vec::new();
+        }
+
+        let root = self.find_root_index_bulk(node_index);
+        let mut members = Vec::new();
+
+        for index in 1..self.nodes.len() {
+            if self.find_root_index_bulk(index) == root {
+                members.push(index);
+            }
+        }
+
+        members.into_iter().map(|index| &self.nodes[index].uuid).collect()
+    }
+
+    /// The number of nodes in the same component as `uuid`, or 0 if it does not exist
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - Unique ID of node whose component size to return
+    ///
+    pub fn component_size(&mut self, uuid: &T) -> usize {
+        let node_index = self.node_index(uuid);
+        if node_index == 0 {
+            return 0;
+        }
+
+        let root = self.find_root_index_bulk(node_index);
+        self.nodes[root].size
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Hash + Eq + Clone + Default + Serialize + for<'de> Deserialize<'de>> Client<T> {
+    /// Writes the fully-processed forest (parents, sizes, name map, set_count) as JSON
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination to write the snapshot to
+    ///
+    pub fn save_to_writer<W: std::io::Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Restores a `Client` previously written by [`Client::save_to_writer`]
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Source to read the snapshot from
+    ///
+    pub fn load_from_reader<R: std::io::Read>(reader: R) -> serde_json::Result<Self> {
+        serde_json::from_reader(reader)
+    }
+}
@@ -1,8 +1,9 @@
 use crate::union_find::client as ufclient;
+use crate::union_find::client::StringClient;
 
 #[test]
 fn constructor() {
-    let client = ufclient::Client::new();
+    let client = StringClient::new();
 
     assert_eq!(1, client.nodes.len());
     assert_eq!(0, client.node_map.len());
@@ -10,8 +11,8 @@ fn constructor() {
 
 #[test]
 fn add_node() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
 
     assert_eq!(2, client.nodes.len());
     assert_eq!(1, client.node_count());
@@ -19,9 +20,9 @@ fn add_node() {
 
 #[test]
 fn duplicate_adds_ignored() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
-    client.add_node("A");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("A"));
 
     assert_eq!(2, client.nodes.len());
     assert_eq!(1, client.node_count());
@@ -29,87 +30,87 @@ fn duplicate_adds_ignored() {
 
 #[test]
 fn node_exists_positive() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
 
-    assert_eq!(true, client.node_exists("A"));
+    assert_eq!(true, client.node_exists(&String::from("A")));
 }
 
 #[test]
 fn node_exists_negative() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
 
-    assert_eq!(false, client.node_exists("foo"));
+    assert_eq!(false, client.node_exists(&String::from("foo")));
 }
 
 #[test]
 fn node_index_positive() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
 
-    assert_eq!(1, client.node_index("A"));
+    assert_eq!(1, client.node_index(&String::from("A")));
 }
 
 #[test]
 fn node_index_negative() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
 
-    assert_eq!(0, client.node_index("foo"));
+    assert_eq!(0, client.node_index(&String::from("foo")));
 }
 
 #[test]
 fn connect_nodes_positive() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
-    client.add_node("B");
-    client.connect_nodes("A", "B");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("B"));
+    client.connect_nodes(&String::from("A"), &String::from("B"));
 
-    assert_eq!(true, client.nodes_connected("A", "B"));
+    assert_eq!(true, client.nodes_connected(&String::from("A"), &String::from("B")));
 }
 
 #[test]
 fn connect_nodes_negative() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
-    client.add_node("B");
-    client.add_node("C");
-    client.connect_nodes("A", "B");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("B"));
+    client.add_node(&String::from("C"));
+    client.connect_nodes(&String::from("A"), &String::from("B"));
 
-    assert_eq!(false, client.nodes_connected("A", "C"));
+    assert_eq!(false, client.nodes_connected(&String::from("A"), &String::from("C")));
 }
 
 #[test]
 fn disjoint_set_count() {
-    let mut client = ufclient::Client::new();
-    client.add_node("A");
-    client.add_node("B");
-    client.add_node("C");
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("B"));
+    client.add_node(&String::from("C"));
     assert_eq!(3, client.disjoint_set_count());
-    client.connect_nodes("A", "B");
+    client.connect_nodes(&String::from("A"), &String::from("B"));
     assert_eq!(2, client.disjoint_set_count());
-    client.connect_nodes("B", "C");
+    client.connect_nodes(&String::from("B"), &String::from("C"));
     assert_eq!(1, client.disjoint_set_count());
-    client.connect_nodes("B", "C");
+    client.connect_nodes(&String::from("B"), &String::from("C"));
     assert_eq!(1, client.disjoint_set_count());
-    client.connect_nodes("A", "A");
+    client.connect_nodes(&String::from("A"), &String::from("A"));
     assert_eq!(1, client.disjoint_set_count());
 }
 
 #[test]
 fn add_nodes_bulk() {
-    let mut client = ufclient::Client::new();
+    let mut client = StringClient::new();
     let nodes = vec![
-        String::from("A"), 
-        String::from("B"), 
+        String::from("A"),
+        String::from("B"),
         String::from("C"),
         String::from("D"),
         String::from("E"),
-        String::from("F"), 
-        String::from("G"), 
-        String::from("H"), 
-        String::from("I"), 
+        String::from("F"),
+        String::from("G"),
+        String::from("H"),
+        String::from("I"),
         String::from("J")
     ];
     client.add_nodes_bulk(nodes);
@@ -119,36 +120,100 @@ fn add_nodes_bulk() {
 
 #[test]
 fn connect_nodes_bulk() {
-    let mut client = ufclient::Client::new();
+    let mut client = StringClient::new();
     let nodes = vec![
-        String::from("A"), 
-        String::from("B"), 
+        String::from("A"),
+        String::from("B"),
         String::from("C"),
         String::from("D"),
         String::from("E"),
-        String::from("F"), 
-        String::from("G"), 
-        String::from("H"), 
-        String::from("I"), 
+        String::from("F"),
+        String::from("G"),
+        String::from("H"),
+        String::from("I"),
         String::from("J")
     ];
     client.add_nodes_bulk(nodes);
 
     let connections = vec![
-        (4, 3),
-        (3, 8),
-        (6, 5),
-        (9, 4),
-        (2, 1),
-        (8, 9),
-        (5, 0),
-        (7, 2),
-        (6, 1),
-        (1, 0),
-        (6, 7)
+        ufclient::BulkConnection::new(4, 3),
+        ufclient::BulkConnection::new(3, 8),
+        ufclient::BulkConnection::new(6, 5),
+        ufclient::BulkConnection::new(9, 4),
+        ufclient::BulkConnection::new(2, 1),
+        ufclient::BulkConnection::new(8, 9),
+        ufclient::BulkConnection::new(5, 0),
+        ufclient::BulkConnection::new(7, 2),
+        ufclient::BulkConnection::new(6, 1),
+        ufclient::BulkConnection::new(1, 0),
+        ufclient::BulkConnection::new(6, 7)
     ];
     client.connect_nodes_bulk(connections);
 
     assert_eq!(10, client.node_count());
     assert_eq!(2, client.disjoint_set_count());
-}
\ No newline at end of file
+}
+
+#[test]
+fn components_groups_nodes_by_root() {
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("B"));
+    client.add_node(&String::from("C"));
+    client.connect_nodes(&String::from("A"), &String::from("B"));
+
+    let components = client.components();
+
+    assert_eq!(2, components.len());
+    let total_members: usize = components.values().map(|members| members.len()).sum();
+    assert_eq!(3, total_members);
+}
+
+#[test]
+fn component_of_returns_connected_nodes() {
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("B"));
+    client.add_node(&String::from("C"));
+    client.connect_nodes(&String::from("A"), &String::from("B"));
+
+    let mut members: Vec<&String> = client.component_of(&String::from("A"));
+    members.sort();
+
+    assert_eq!(vec![&String::from("A"), &String::from("B")], members);
+}
+
+#[test]
+fn component_of_unknown_node_is_empty() {
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+
+    assert_eq!(0, client.component_of(&String::from("missing")).len());
+}
+
+#[test]
+fn component_size_counts_members() {
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("B"));
+    client.add_node(&String::from("C"));
+    client.connect_nodes(&String::from("A"), &String::from("B"));
+
+    assert_eq!(2, client.component_size(&String::from("A")));
+    assert_eq!(1, client.component_size(&String::from("C")));
+    assert_eq!(0, client.component_size(&String::from("missing")));
+}
+
+#[test]
+fn for_each_component_visits_every_node() {
+    let mut client = StringClient::new();
+    client.add_node(&String::from("A"));
+    client.add_node(&String::from("B"));
+    client.add_node(&String::from("C"));
+    client.connect_nodes(&String::from("A"), &String::from("B"));
+
+    let mut visited = Vec::new();
+    client.for_each_component(|root, uuid| visited.push((root, uuid.clone())));
+
+    assert_eq!(3, visited.len());
+}
@@ -0,0 +1,224 @@
+#[cfg(test)]
+#[path = "concurrent_tests.rs"]
+mod concurrent_tests;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::union_find::client::BulkConnection;
+
+/// A thread-safe union-find for parallel bulk connection
+///
+/// Parent links and ranks are stored as atomics so disjoint edges can be
+/// unioned from multiple threads without a global lock. `find` performs
+/// path halving with compare-and-swap, tolerating lost races since any
+/// thread that shortcuts a link leaves the structure correct either way.
+/// Unlike [`crate::union_find::client::Client`], nodes are loaded once up
+/// front via [`ConcurrentClient::add_nodes_bulk`] before any concurrent
+/// union begins.
+///
+/// # Examples
+///
+/// ``` rust
+/// extern crate cozad_union_find;
+/// use cozad_union_find::union_find::concurrent::ConcurrentClient;
+/// use cozad_union_find::union_find::client::BulkConnection as ufconnection;
+///
+/// fn main() {
+///    let mut client = ConcurrentClient::new();
+///    client.add_nodes_bulk(vec![
+///        String::from("A"),
+///        String::from("B"),
+///        String::from("C")
+///    ]);
+///
+///    client.connect_edges_parallel(vec![
+///        ufconnection { a: 0, b: 1 },
+///        ufconnection { a: 1, b: 2 }
+///    ]);
+///
+///    println!("\nDisjoint sets found: {}", client.disjoint_set_count());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentClient {
+    /// Parent index for each node, atomically updated during path halving and union
+    parents: Vec<AtomicUsize>,
+    /// Union-by-rank tree heights
+    ranks: Vec<AtomicUsize>,
+    /// Map of names to index
+    node_map: HashMap<String, usize>,
+    /// Number of disjoint sets, decremented only on a successful merge
+    set_count: AtomicUsize
+}
+
+impl ConcurrentClient {
+    /// Constructs a new, empty `ConcurrentClient`
+    pub fn new() -> Self {
+        let mut parents = Vec::new();
+        let mut ranks = Vec::new();
+
+        parents.push(AtomicUsize::new(0));
+        ranks.push(AtomicUsize::new(0));
+
+        ConcurrentClient {
+            parents,
+            ranks,
+            node_map: HashMap::new(),
+            set_count: AtomicUsize::new(0)
+        }
+    }
+
+    /// Adds a multiple nodes with a single call
+    ///
+    /// Must complete before any call to [`ConcurrentClient::union`] or
+    /// [`ConcurrentClient::connect_edges_parallel`], since node storage is
+    /// not safe to grow concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid_list` - Collection of unique IDs
+    ///
+    pub fn add_nodes_bulk(&mut self, uuid_list: Vec<String>) {
+        for uuid in uuid_list.iter() {
+            let index = self.parents.len();
+            self.node_map.insert(String::from(uuid), index);
+            self.parents.push(AtomicUsize::new(index));
+            self.ranks.push(AtomicUsize::new(0));
+            *self.set_count.get_mut() += 1;
+        }
+    }
+
+    /// Finds the connected node with no parent, path-halving as it walks
+    ///
+    /// # Arguments
+    ///
+    /// * `node_index` - Index of node to find the root of
+    ///
+    pub fn find(&self, node_index: usize) -> usize {
+        let mut current = node_index;
+
+        loop {
+            let parent = self.parents[current].load(Ordering::Acquire);
+            if parent == current {
+                return current;
+            }
+
+            let grandparent = self.parents[parent].load(Ordering::Acquire);
+            // Tolerate a lost race here: if another thread already moved
+            // `current` on, this CAS simply fails and we continue from
+            // the grandparent we just observed.
+            let _ = self.parents[current].compare_exchange(
+                parent,
+                grandparent,
+                Ordering::AcqRel,
+                Ordering::Relaxed
+            );
+
+            current = grandparent;
+        }
+    }
+
+    /// Unions the sets containing the two node indexes, retrying on a lost CAS race
+    ///
+    /// # Arguments
+    ///
+    /// * `a_index` - Index of first node
+    /// * `b_index` - Index of second node
+    ///
+    pub fn union(&self, a_index: usize, b_index: usize) -> bool {
+        loop {
+            let root_a = self.find(a_index);
+            let root_b = self.find(b_index);
+
+            if root_a == root_b {
+                return false;
+            }
+
+            let rank_a = self.ranks[root_a].load(Ordering::Acquire);
+            let rank_b = self.ranks[root_b].load(Ordering::Acquire);
+
+            let (child, parent) = if rank_a < rank_b {
+                (root_a, root_b)
+            } else {
+                (root_b, root_a)
+            };
+
+            if self.parents[child]
+                .compare_exchange(child, parent, Ordering::AcqRel, Ordering::Relaxed)
+                .is_err()
+            {
+                // Lost the race to another thread merging the same roots; re-find and retry.
+                continue;
+            }
+
+            if rank_a == rank_b {
+                self.ranks[parent].fetch_add(1, Ordering::AcqRel);
+            }
+
+            self.set_count.fetch_sub(1, Ordering::AcqRel);
+            return true;
+        }
+    }
+
+    /// Splits a collection of edges across a thread pool and unions them in parallel
+    ///
+    /// # Arguments
+    ///
+    /// * `connections` - Collection of graph connections
+    ///
+    pub fn connect_edges_parallel(&self, connections: Vec<BulkConnection>) {
+        let thread_count = std::thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1);
+        let chunk_size = (connections.len() / thread_count).max(1);
+
+        std::thread::scope(|scope| {
+            for chunk in connections.chunks(chunk_size) {
+                scope.spawn(move || {
+                    for connection in chunk {
+                        self.union(connection.a + 1, connection.b + 1);
+                    }
+                });
+            }
+        });
+    }
+
+    /// The number of sets that share no connection with another set
+    pub fn disjoint_set_count(&self) -> usize {
+        self.set_count.load(Ordering::Acquire)
+    }
+
+    /// The number of unique nodes in the graph
+    pub fn node_count(&self) -> usize {
+        self.parents.len() - 1
+    }
+
+    /// Gets the index for a node with a given unique ID
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - Unique ID of node
+    ///
+    pub fn node_index(&self, uuid: &str) -> usize {
+        let node_uuid = String::from(uuid);
+        if self.node_map.contains_key(&node_uuid) {
+            *self.node_map.get(&node_uuid).unwrap()
+        } else {
+            0
+        }
+    }
+
+    /// Determines if two nodes are connected through any path
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid_a` - Unique ID of first connection
+    /// * `uuid_b` - Unique ID of second connection
+    ///
+    pub fn nodes_connected(&self, uuid_a: &str, uuid_b: &str) -> bool {
+        let uuid_a_index = self.node_index(uuid_a);
+        let uuid_b_index = self.node_index(uuid_b);
+
+        uuid_a_index > 0 && self.find(uuid_a_index) == self.find(uuid_b_index)
+    }
+}
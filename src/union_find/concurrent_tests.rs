@@ -0,0 +1,99 @@
+use crate::union_find::concurrent::ConcurrentClient;
+use crate::union_find::client::BulkConnection;
+use std::sync::Arc;
+
+fn sample_client() -> ConcurrentClient {
+    let mut client = ConcurrentClient::new();
+    client.add_nodes_bulk(vec![
+        String::from("A"),
+        String::from("B"),
+        String::from("C"),
+        String::from("D"),
+        String::from("E")
+    ]);
+    client
+}
+
+#[test]
+fn constructor() {
+    let client = ConcurrentClient::new();
+
+    assert_eq!(0, client.node_count());
+    assert_eq!(0, client.disjoint_set_count());
+}
+
+#[test]
+fn add_nodes_bulk() {
+    let client = sample_client();
+
+    assert_eq!(5, client.node_count());
+    assert_eq!(5, client.disjoint_set_count());
+}
+
+#[test]
+fn union_merges_two_sets() {
+    let client = sample_client();
+
+    assert_eq!(true, client.union(1, 2));
+    assert_eq!(4, client.disjoint_set_count());
+    assert_eq!(true, client.nodes_connected("A", "B"));
+}
+
+#[test]
+fn union_of_already_connected_nodes_is_a_no_op() {
+    let client = sample_client();
+
+    client.union(1, 2);
+    assert_eq!(false, client.union(1, 2));
+    assert_eq!(4, client.disjoint_set_count());
+}
+
+#[test]
+fn connect_edges_parallel_merges_all_disjoint_edges() {
+    let mut client = sample_client();
+    client.add_nodes_bulk(vec![
+        String::from("F"),
+        String::from("G"),
+        String::from("H"),
+        String::from("I"),
+        String::from("J")
+    ]);
+
+    let connections = vec![
+        BulkConnection::new(4, 3),
+        BulkConnection::new(3, 8),
+        BulkConnection::new(6, 5),
+        BulkConnection::new(9, 4),
+        BulkConnection::new(2, 1),
+        BulkConnection::new(8, 9),
+        BulkConnection::new(5, 0),
+        BulkConnection::new(7, 2),
+        BulkConnection::new(6, 1),
+        BulkConnection::new(1, 0),
+        BulkConnection::new(6, 7)
+    ];
+    client.connect_edges_parallel(connections);
+
+    assert_eq!(10, client.node_count());
+    assert_eq!(2, client.disjoint_set_count());
+}
+
+#[test]
+fn union_from_multiple_threads_converges_to_one_set() {
+    let client = Arc::new(sample_client());
+    let mut handles = Vec::new();
+
+    for pair in [(1, 2), (2, 3), (3, 4), (4, 5)] {
+        let client = Arc::clone(&client);
+        handles.push(std::thread::spawn(move || {
+            client.union(pair.0, pair.1);
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(1, client.disjoint_set_count());
+    assert_eq!(true, client.nodes_connected("A", "E"));
+}
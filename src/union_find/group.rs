@@ -0,0 +1,35 @@
+/// An abelian group over an associated value type
+///
+/// Implementations provide the identity element along with `combine` and
+/// `invert` operations that obey the usual group laws (and commute, since
+/// callers rely on being able to re-order terms when re-basing a potential
+/// to a new root).
+pub trait Group {
+    /// The value carried by the group
+    type T;
+
+    /// The identity element, e.g. zero for addition
+    fn identity() -> Self::T;
+
+    /// Combines two values, e.g. addition
+    fn combine(a: &Self::T, b: &Self::T) -> Self::T;
+
+    /// The inverse of a value, e.g. negation
+    fn invert(a: &Self::T) -> Self::T;
+}
+
+impl Group for i64 {
+    type T = i64;
+
+    fn identity() -> i64 {
+        0
+    }
+
+    fn combine(a: &i64, b: &i64) -> i64 {
+        a + b
+    }
+
+    fn invert(a: &i64) -> i64 {
+        -a
+    }
+}
@@ -0,0 +1,4 @@
+pub mod client;
+pub mod concurrent;
+pub mod group;
+pub mod weighted;
@@ -0,0 +1,242 @@
+#[cfg(test)]
+#[path = "weighted_tests.rs"]
+mod weighted_tests;
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use crate::union_find::group::Group;
+
+/// A node in the weighted graph
+struct Node<W: Group> {
+    /// Unique ID of the node
+    pub uuid: String,
+    /// Index for the node's parent
+    pub parent_index: usize,
+    /// Index where the node is stored
+    pub index: usize,
+    /// Number items in chain
+    pub size: usize,
+    /// Value of this node relative to its parent, drawn from the group `W`
+    pub potential: W::T
+}
+
+/// A client that manages a graph of nodes connected by values drawn from an
+/// abelian group `W`, in addition to plain connectivity
+///
+/// This mirrors [`crate::union_find::client::Client`], but each connection
+/// also carries a value (e.g. an offset, an ordering, a parity) and
+/// `difference` recovers the accumulated value between any two connected
+/// nodes.
+///
+/// # Examples
+///
+/// ``` rust
+/// extern crate cozad_union_find;
+/// use cozad_union_find::union_find::weighted::WeightedClient;
+///
+/// fn main() {
+///    let mut client: WeightedClient<i64> = WeightedClient::new();
+///
+///    client.add_node("A");
+///    client.add_node("B");
+///    client.add_node("C");
+///
+///    client.connect_nodes_weighted("A", "B", 3);
+///    client.connect_nodes_weighted("B", "C", 2);
+///
+///    println!("\nA - C = {:?}", client.difference("A", "C"));
+/// }
+///```
+pub struct WeightedClient<W: Group> {
+    /// Storage for nodes in the graph
+    nodes: Vec<Node<W>>,
+    /// Map of names to index
+    node_map: HashMap<String, usize>,
+    /// Number of disjoint sets
+    set_count: usize,
+    phantom: PhantomData<W>
+}
+
+impl<W: Group> WeightedClient<W>
+where
+    W::T: Clone + PartialEq
+{
+    /// Constructs a new `WeightedClient`
+    pub fn new() -> Self {
+        let node_map = HashMap::new();
+        let mut nodes = Vec::new();
+
+        let root_node = Node {
+            uuid: String::from("root"),
+            parent_index: 0,
+            index: 0,
+            size: 0,
+            potential: W::identity()
+        };
+        nodes.push(root_node);
+
+        WeightedClient {
+            nodes,
+            node_map,
+            set_count: 0,
+            phantom: PhantomData
+        }
+    }
+
+    /// Adds a node with given unique id
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - Unique ID of node
+    ///
+    pub fn add_node(&mut self, uuid: &str) {
+        if !self.node_exists(uuid) {
+            let node = Node {
+                uuid: String::from(uuid),
+                parent_index: self.nodes.len(),
+                index: self.nodes.len(),
+                size: 1,
+                potential: W::identity()
+            };
+            self.node_map.insert(String::from(uuid), node.index);
+            self.nodes.push(node);
+            self.set_count += 1;
+        }
+    }
+
+    /// Connects two nodes, recording `w` as the value of `a` relative to `b`
+    ///
+    /// Returns `false` without modifying the graph when either node does not
+    /// exist, or when `a` and `b` are already connected and the existing
+    /// difference between them contradicts `w`; returns `true` otherwise (including when the nodes
+    /// were already connected and `w` agrees with the existing difference).
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid_a` - Unique id of first node
+    /// * `uuid_b` - Unique id of second node
+    /// * `w` - Value of `uuid_a` relative to `uuid_b`
+    ///
+    pub fn connect_nodes_weighted(&mut self, uuid_a: &str, uuid_b: &str, w: W::T) -> bool {
+        let uuid_a_index = self.node_index(uuid_a);
+        let uuid_b_index = self.node_index(uuid_b);
+
+        if uuid_a_index == 0 || uuid_b_index == 0 {
+            return false;
+        }
+
+        let (uuid_a_root, potential_a) = self.find_root_with_potential(uuid_a_index);
+        let (uuid_b_root, potential_b) = self.find_root_with_potential(uuid_b_index);
+
+        if uuid_a_root == uuid_b_root {
+            let actual = W::combine(&potential_a, &W::invert(&potential_b));
+            return actual == w;
+        }
+
+        let node_slice = &mut self.nodes[..];
+
+        if node_slice[uuid_a_root].size < node_slice[uuid_b_root].size {
+            // `a`'s tree joins `b`'s tree; `b`'s root stays the representative
+            let offset = W::combine(&w, &W::combine(&potential_b, &W::invert(&potential_a)));
+            node_slice[uuid_a_root].parent_index = uuid_b_root;
+            node_slice[uuid_a_root].potential = offset;
+            node_slice[uuid_b_root].size += node_slice[uuid_a_root].size;
+        } else {
+            // `b`'s tree joins `a`'s tree; `a`'s root stays the representative
+            let offset = W::combine(&W::combine(&potential_a, &W::invert(&potential_b)), &W::invert(&w));
+            node_slice[uuid_b_root].parent_index = uuid_a_root;
+            node_slice[uuid_b_root].potential = offset;
+            node_slice[uuid_a_root].size += node_slice[uuid_b_root].size;
+        }
+
+        self.set_count -= 1;
+        true
+    }
+
+    /// The value of `uuid_a` relative to `uuid_b`, or `None` if they are not connected
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid_a` - Unique id of first node
+    /// * `uuid_b` - Unique id of second node
+    ///
+    pub fn difference(&mut self, uuid_a: &str, uuid_b: &str) -> Option<W::T> {
+        let uuid_a_index = self.node_index(uuid_a);
+        let uuid_b_index = self.node_index(uuid_b);
+
+        if uuid_a_index == 0 || uuid_b_index == 0 {
+            return None;
+        }
+
+        let (uuid_a_root, potential_a) = self.find_root_with_potential(uuid_a_index);
+        let (uuid_b_root, potential_b) = self.find_root_with_potential(uuid_b_index);
+
+        if uuid_a_root != uuid_b_root {
+            return None;
+        }
+
+        Some(W::combine(&potential_a, &W::invert(&potential_b)))
+    }
+
+    /// Finds the root of a node along with its accumulated potential relative to that root,
+    /// path-compressing and re-basing potentials along the way
+    ///
+    /// # Arguments
+    ///
+    /// * `node_index` - Index of node to find the root of
+    ///
+    fn find_root_with_potential(&mut self, node_index: usize) -> (usize, W::T) {
+        let parent_index = self.nodes[node_index].parent_index;
+
+        if parent_index == node_index {
+            return (node_index, W::identity());
+        }
+
+        let node_potential = self.nodes[node_index].potential.clone();
+        let (root_index, parent_potential) = self.find_root_with_potential(parent_index);
+        let total_potential = W::combine(&node_potential, &parent_potential);
+
+        if parent_index != root_index {
+            self.nodes[node_index].parent_index = root_index;
+            self.nodes[node_index].potential = total_potential.clone();
+        }
+
+        (root_index, total_potential)
+    }
+
+    /// The number of sets that share no connection with another set
+    pub fn disjoint_set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// The number of unique nodes in the graph
+    pub fn node_count(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    /// Determines if a node exists by the given name
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - Unique ID of node
+    ///
+    pub fn node_exists(&self, uuid: &str) -> bool {
+        let node_uuid = String::from(uuid);
+        self.node_map.contains_key(&node_uuid)
+    }
+
+    /// Gets the index for a node with a given unique ID
+    ///
+    /// # Arguments
+    ///
+    /// * `uuid` - Unique ID of node
+    ///
+    pub fn node_index(&self, uuid: &str) -> usize {
+        let node_uuid = String::from(uuid);
+        if self.node_map.contains_key(&node_uuid) {
+            *self.node_map.get(&node_uuid).unwrap()
+        } else {
+            0
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::union_find::weighted::WeightedClient;
+
+#[test]
+fn constructor() {
+    let client: WeightedClient<i64> = WeightedClient::new();
+
+    assert_eq!(0, client.node_count());
+    assert_eq!(0, client.disjoint_set_count());
+}
+
+#[test]
+fn add_node() {
+    let mut client: WeightedClient<i64> = WeightedClient::new();
+    client.add_node("A");
+
+    assert_eq!(1, client.node_count());
+    assert_eq!(1, client.disjoint_set_count());
+}
+
+#[test]
+fn difference_unconnected_is_none() {
+    let mut client: WeightedClient<i64> = WeightedClient::new();
+    client.add_node("A");
+    client.add_node("B");
+
+    assert_eq!(None, client.difference("A", "B"));
+}
+
+#[test]
+fn connect_nodes_weighted_tracks_difference() {
+    let mut client: WeightedClient<i64> = WeightedClient::new();
+    client.add_node("A");
+    client.add_node("B");
+
+    assert_eq!(true, client.connect_nodes_weighted("A", "B", 3));
+    assert_eq!(Some(3), client.difference("A", "B"));
+    assert_eq!(Some(-3), client.difference("B", "A"));
+}
+
+#[test]
+fn connect_nodes_weighted_accumulates_through_chain() {
+    let mut client: WeightedClient<i64> = WeightedClient::new();
+    client.add_node("A");
+    client.add_node("B");
+    client.add_node("C");
+
+    client.connect_nodes_weighted("A", "B", 3);
+    client.connect_nodes_weighted("B", "C", 2);
+
+    assert_eq!(Some(5), client.difference("A", "C"));
+    assert_eq!(1, client.disjoint_set_count());
+}
+
+#[test]
+fn connect_nodes_weighted_accepts_consistent_redundant_edge() {
+    let mut client: WeightedClient<i64> = WeightedClient::new();
+    client.add_node("A");
+    client.add_node("B");
+    client.add_node("C");
+
+    client.connect_nodes_weighted("A", "B", 3);
+    client.connect_nodes_weighted("B", "C", 2);
+
+    assert_eq!(true, client.connect_nodes_weighted("A", "C", 5));
+    assert_eq!(1, client.disjoint_set_count());
+}
+
+#[test]
+fn connect_nodes_weighted_rejects_contradictory_edge() {
+    let mut client: WeightedClient<i64> = WeightedClient::new();
+    client.add_node("A");
+    client.add_node("B");
+    client.add_node("C");
+
+    client.connect_nodes_weighted("A", "B", 3);
+    client.connect_nodes_weighted("B", "C", 2);
+
+    assert_eq!(false, client.connect_nodes_weighted("A", "C", 6));
+    assert_eq!(Some(5), client.difference("A", "C"));
+}
+
+#[test]
+fn unknown_node_is_rejected() {
+    let mut client: WeightedClient<i64> = WeightedClient::new();
+    client.add_node("A");
+
+    assert_eq!(false, client.connect_nodes_weighted("A", "missing", 1));
+    assert_eq!(None, client.difference("A", "missing"));
+}